@@ -14,12 +14,20 @@
 //! - `ACI_BASE_URL` - API base URL (default: http://localhost:3000)
 //! - `ACI_API_KEY` - API key for authentication
 
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
 use chrono::{DateTime, Utc};
+use futures_util::{SinkExt, Stream, StreamExt};
+use rand::Rng;
+use sha2::{Digest, Sha256};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::env;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::Message;
 
 // =============================================================================
 // Types
@@ -66,6 +74,95 @@ pub enum Decision {
     Escalate,
 }
 
+/// A single capability scope, such as `data:read` or `compute:execute`.
+/// Scopes are finer-grained than the eight fixed [`AgentRole`] variants.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Scope(String);
+
+impl Scope {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<S: Into<String>> From<S> for Scope {
+    fn from(value: S) -> Self {
+        Self::new(value)
+    }
+}
+
+/// A parsed, set-backed collection of space-delimited capability strings,
+/// round-tripping through `serde` as a single canonical space-separated
+/// string (the same convention OAuth-style scope strings use).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Scopes(std::collections::BTreeSet<Scope>);
+
+impl Scopes {
+    /// Parses a space-delimited scope string, e.g. `"data:read compute:execute"`.
+    pub fn parse(value: &str) -> Self {
+        value.split_whitespace().map(Scope::new).collect()
+    }
+
+    pub fn contains(&self, scope: &str) -> bool {
+        self.0.iter().any(|s| s.as_str() == scope)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Scope> {
+        self.0.iter()
+    }
+}
+
+impl<S: Into<Scope>> FromIterator<S> for Scopes {
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        Self(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl std::fmt::Display for Scopes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rendered = self
+            .0
+            .iter()
+            .map(Scope::as_str)
+            .collect::<Vec<_>>()
+            .join(" ");
+        f.write_str(&rendered)
+    }
+}
+
+impl Serialize for Scopes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Scopes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        Ok(Scopes::parse(&raw))
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RoleGateRequest {
@@ -74,6 +171,8 @@ pub struct RoleGateRequest {
     pub tier: TrustTier,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub context: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Scopes>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -87,6 +186,11 @@ pub struct RoleGateResponse {
     pub provenance_id: Option<String>,
     #[serde(default)]
     pub required_tier: Option<TrustTier>,
+    /// Present on `Deny`/`Escalate` decisions when `scopes` were requested.
+    #[serde(default)]
+    pub granted_scopes: Option<Scopes>,
+    #[serde(default)]
+    pub missing_scopes: Option<Scopes>,
 }
 
 #[derive(Debug, Serialize)]
@@ -156,6 +260,645 @@ pub struct AlertStats {
     pub by_severity: HashMap<String, i32>,
 }
 
+// =============================================================================
+// Rate Limiting
+// =============================================================================
+
+/// Endpoint classes that are rate limited independently of one another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum LimitType {
+    RoleGates,
+    Ceiling,
+    Stats,
+}
+
+impl LimitType {
+    fn classify(path: &str) -> Self {
+        if path.contains("role-gates") {
+            LimitType::RoleGates
+        } else if path.contains("ceiling") {
+            LimitType::Ceiling
+        } else {
+            LimitType::Stats
+        }
+    }
+}
+
+/// Tracks the remaining allowance and reset time for a single `LimitType`,
+/// as reported by the `X-RateLimit-*` response headers.
+#[derive(Debug, Clone)]
+struct RateLimitBucket {
+    remaining: u32,
+    reset_at: Instant,
+}
+
+impl Default for RateLimitBucket {
+    fn default() -> Self {
+        Self {
+            remaining: u32::MAX,
+            reset_at: Instant::now(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct RateLimiter {
+    buckets: tokio::sync::Mutex<HashMap<LimitType, RateLimitBucket>>,
+}
+
+impl RateLimiter {
+    /// Sleeps until the bucket's reset time if it has been exhausted.
+    async fn wait_if_exhausted(&self, limit: LimitType) {
+        let sleep_for = {
+            let buckets = self.buckets.lock().await;
+            match buckets.get(&limit) {
+                Some(bucket) if bucket.remaining == 0 && bucket.reset_at > Instant::now() => {
+                    Some(bucket.reset_at - Instant::now())
+                }
+                _ => None,
+            }
+        };
+
+        if let Some(duration) = sleep_for {
+            tokio::time::sleep(duration).await;
+        }
+    }
+
+    async fn update_from_headers(&self, limit: LimitType, headers: &reqwest::header::HeaderMap) {
+        let remaining = headers
+            .get("X-RateLimit-Remaining")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u32>().ok());
+        let reset_secs = headers
+            .get("X-RateLimit-Reset")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
+
+        if remaining.is_none() && reset_secs.is_none() {
+            return;
+        }
+
+        let mut buckets = self.buckets.lock().await;
+        let bucket = buckets.entry(limit).or_default();
+        if let Some(remaining) = remaining {
+            bucket.remaining = remaining;
+        }
+        if let Some(secs) = reset_secs {
+            bucket.reset_at = Instant::now() + Duration::from_secs(secs);
+        }
+    }
+}
+
+/// Tunables for retry/backoff and rate-limit behavior on [`ACIClient`].
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    pub max_retries: u32,
+    pub base_backoff: Duration,
+    pub max_backoff: Duration,
+    pub respect_rate_limit_headers: bool,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            base_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(30),
+            respect_rate_limit_headers: true,
+        }
+    }
+}
+
+/// Parses a `Retry-After` header value, which per RFC 7231 is either an
+/// integer number of seconds or an HTTP-date.
+fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+
+    if let Ok(secs) = value.trim().parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let when = DateTime::parse_from_rfc2822(value.trim()).ok()?;
+    (when.with_timezone(&Utc) - Utc::now()).to_std().ok()
+}
+
+/// Capped exponential backoff with full jitter.
+fn backoff_duration(attempt: u32, base: Duration, max: Duration) -> Duration {
+    let exponent = attempt.min(16);
+    let scaled = base.saturating_mul(1u32 << exponent);
+    let capped = scaled.min(max);
+    let jitter = rand::thread_rng().gen_range(0..=capped.as_millis() as u64);
+    Duration::from_millis(jitter)
+}
+
+// =============================================================================
+// Authentication
+// =============================================================================
+
+/// How `ACIClient` authenticates itself. A static API key and OAuth2 bearer
+/// tokens are mutually exclusive for a given client instance.
+pub enum Auth {
+    ApiKey(String),
+    OAuth2(Box<OAuth2Auth>),
+}
+
+/// Issuer metadata for an OAuth2 authorization server.
+#[derive(Clone)]
+pub struct OAuth2Config {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub introspection_endpoint: Option<String>,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+}
+
+impl std::fmt::Debug for OAuth2Config {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OAuth2Config")
+            .field("issuer", &self.issuer)
+            .field("authorization_endpoint", &self.authorization_endpoint)
+            .field("token_endpoint", &self.token_endpoint)
+            .field("introspection_endpoint", &self.introspection_endpoint)
+            .field("client_id", &self.client_id)
+            .field(
+                "client_secret",
+                &self.client_secret.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
+}
+
+/// The PKCE code-challenge derivation method, per RFC 7636.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PkceMethod {
+    S256,
+    Plain,
+}
+
+impl PkceMethod {
+    fn as_str(self) -> &'static str {
+        match self {
+            PkceMethod::S256 => "S256",
+            PkceMethod::Plain => "plain",
+        }
+    }
+}
+
+/// A PKCE code verifier/challenge pair for the `authorization_code` flow,
+/// letting public clients authenticate without a client secret.
+pub struct Pkce {
+    verifier: String,
+    method: PkceMethod,
+}
+
+impl Pkce {
+    pub fn new() -> Self {
+        Self::with_method(PkceMethod::S256)
+    }
+
+    pub fn with_method(method: PkceMethod) -> Self {
+        Self {
+            verifier: generate_code_verifier(),
+            method,
+        }
+    }
+
+    /// The `code_challenge` to attach to the authorization request.
+    pub fn code_challenge(&self) -> String {
+        match self.method {
+            PkceMethod::S256 => URL_SAFE_NO_PAD.encode(Sha256::digest(self.verifier.as_bytes())),
+            PkceMethod::Plain => self.verifier.clone(),
+        }
+    }
+
+    fn method_str(&self) -> &'static str {
+        self.method.as_str()
+    }
+
+    fn into_verifier(self) -> String {
+        self.verifier
+    }
+}
+
+impl Default for Pkce {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Generates a random code verifier from the unreserved character set
+/// allowed by RFC 7636 (43-128 characters; we use 64).
+fn generate_code_verifier() -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-._~";
+    let mut rng = rand::thread_rng();
+    (0..64)
+        .map(|_| ALPHABET[rng.gen_range(0..ALPHABET.len())] as char)
+        .collect()
+}
+
+/// The grants `OAuth2Auth` knows how to perform against the token endpoint.
+#[derive(Clone)]
+pub enum GrantType {
+    AuthorizationCode {
+        code: String,
+        redirect_uri: String,
+    },
+    RefreshToken {
+        refresh_token: String,
+    },
+}
+
+impl std::fmt::Debug for GrantType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GrantType::AuthorizationCode { redirect_uri, .. } => f
+                .debug_struct("AuthorizationCode")
+                .field("code", &"<redacted>")
+                .field("redirect_uri", redirect_uri)
+                .finish(),
+            GrantType::RefreshToken { .. } => f
+                .debug_struct("RefreshToken")
+                .field("refresh_token", &"<redacted>")
+                .finish(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    refresh_token: Option<String>,
+    #[serde(default = "default_expires_in")]
+    expires_in: u64,
+}
+
+impl std::fmt::Debug for TokenResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokenResponse")
+            .field("access_token", &"<redacted>")
+            .field(
+                "refresh_token",
+                &self.refresh_token.as_ref().map(|_| "<redacted>"),
+            )
+            .field("expires_in", &self.expires_in)
+            .finish()
+    }
+}
+
+fn default_expires_in() -> u64 {
+    3600
+}
+
+#[derive(Clone)]
+struct TokenState {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_at: Instant,
+}
+
+impl std::fmt::Debug for TokenState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokenState")
+            .field("access_token", &"<redacted>")
+            .field(
+                "refresh_token",
+                &self.refresh_token.as_ref().map(|_| "<redacted>"),
+            )
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+/// How far ahead of expiry a cached access token is considered stale, so a
+/// refresh happens before the server would reject it.
+const TOKEN_EXPIRY_SKEW: Duration = Duration::from_secs(30);
+
+/// Holds the current access/refresh token pair and refreshes it transparently.
+pub struct OAuth2Auth {
+    config: OAuth2Config,
+    state: tokio::sync::Mutex<Option<TokenState>>,
+    pending_verifier: tokio::sync::Mutex<Option<String>>,
+    /// Serializes refreshes so concurrent callers share a single in-flight
+    /// refresh instead of each racing the token endpoint with the same
+    /// (possibly single-use) refresh token.
+    refresh_lock: tokio::sync::Mutex<()>,
+}
+
+impl OAuth2Auth {
+    pub fn new(config: OAuth2Config) -> Self {
+        Self {
+            config,
+            state: tokio::sync::Mutex::new(None),
+            pending_verifier: tokio::sync::Mutex::new(None),
+            refresh_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Builds the authorization-request URL for the `authorization_code`
+    /// flow with PKCE attached, stashing the code verifier so it's supplied
+    /// automatically the next time `authenticate` performs that grant.
+    pub async fn begin_authorization(
+        &self,
+        redirect_uri: impl Into<String>,
+        scope: Option<&str>,
+    ) -> Result<String, ACIError> {
+        let pkce = Pkce::new();
+        let challenge = pkce.code_challenge();
+        let method = pkce.method_str();
+        let redirect_uri = redirect_uri.into();
+
+        let mut url = reqwest::Url::parse(&self.config.authorization_endpoint).map_err(|e| {
+            ACIError::ApiError {
+                status: 0,
+                message: format!("invalid authorization endpoint: {e}"),
+            }
+        })?;
+        {
+            let mut pairs = url.query_pairs_mut();
+            pairs.append_pair("response_type", "code");
+            pairs.append_pair("client_id", &self.config.client_id);
+            pairs.append_pair("redirect_uri", &redirect_uri);
+            pairs.append_pair("code_challenge", &challenge);
+            pairs.append_pair("code_challenge_method", method);
+            if let Some(scope) = scope {
+                pairs.append_pair("scope", scope);
+            }
+        }
+
+        let mut pending = self.pending_verifier.lock().await;
+        *pending = Some(pkce.into_verifier());
+
+        Ok(url.to_string())
+    }
+
+    /// Performs the given grant against the token endpoint, storing the
+    /// resulting access/refresh token pair.
+    pub async fn authenticate(&self, client: &Client, grant: GrantType) -> Result<(), ACIError> {
+        let mut params = vec![("client_id", self.config.client_id.clone())];
+        if let Some(secret) = &self.config.client_secret {
+            params.push(("client_secret", secret.clone()));
+        }
+        match &grant {
+            GrantType::AuthorizationCode { code, redirect_uri } => {
+                params.push(("grant_type", "authorization_code".to_string()));
+                params.push(("code", code.clone()));
+                params.push(("redirect_uri", redirect_uri.clone()));
+
+                let verifier = self.pending_verifier.lock().await.take();
+                if let Some(verifier) = verifier {
+                    params.push(("code_verifier", verifier));
+                }
+            }
+            GrantType::RefreshToken { refresh_token } => {
+                params.push(("grant_type", "refresh_token".to_string()));
+                params.push(("refresh_token", refresh_token.clone()));
+            }
+        }
+
+        self.exchange(client, &params).await
+    }
+
+    /// Whether the cached token is missing or within the expiry skew window.
+    fn is_expired(state: &Option<TokenState>) -> bool {
+        match state.as_ref() {
+            Some(s) => Instant::now() + TOKEN_EXPIRY_SKEW >= s.expires_at,
+            None => true,
+        }
+    }
+
+    /// Returns a valid access token, transparently refreshing it first if it
+    /// is missing or within the expiry skew window.
+    async fn access_token(&self, client: &Client) -> Result<String, ACIError> {
+        let needs_refresh = {
+            let state = self.state.lock().await;
+            Self::is_expired(&state)
+        };
+
+        if needs_refresh {
+            self.refresh(client).await?;
+        }
+
+        let state = self.state.lock().await;
+        state
+            .as_ref()
+            .map(|s| s.access_token.clone())
+            .ok_or_else(|| ACIError::ApiError {
+                status: 0,
+                message: "OAuth2 auth has no access token; authenticate first".to_string(),
+            })
+    }
+
+    /// Replays the stored refresh token grant to obtain a new token pair.
+    /// Refreshes the cached token pair, single-flighted via `refresh_lock`
+    /// so concurrent callers share one in-flight refresh instead of each
+    /// racing the token endpoint with the same refresh token.
+    async fn refresh(&self, client: &Client) -> Result<(), ACIError> {
+        let (starting_access_token, starting_refresh_token) = {
+            let state = self.state.lock().await;
+            (
+                state.as_ref().map(|s| s.access_token.clone()),
+                state.as_ref().and_then(|s| s.refresh_token.clone()),
+            )
+        };
+
+        let _guard = self.refresh_lock.lock().await;
+
+        // Someone else may have already refreshed while we waited for the
+        // lock; if the stored access token has since changed, their
+        // refresh covers us and we don't need our own.
+        let current_access_token = {
+            let state = self.state.lock().await;
+            state.as_ref().map(|s| s.access_token.clone())
+        };
+        if current_access_token != starting_access_token {
+            return Ok(());
+        }
+
+        let refresh_token = starting_refresh_token.ok_or_else(|| ACIError::ApiError {
+            status: 0,
+            message: "no refresh token available to refresh OAuth2 auth".to_string(),
+        })?;
+
+        self.authenticate(client, GrantType::RefreshToken { refresh_token })
+            .await
+    }
+
+    async fn exchange(&self, client: &Client, params: &[(&str, String)]) -> Result<(), ACIError> {
+        let resp = client
+            .post(&self.config.token_endpoint)
+            .form(params)
+            .send()
+            .await?;
+
+        if !resp.status().is_success() {
+            let status = resp.status().as_u16();
+            let message = resp.text().await.unwrap_or_default();
+            return Err(ACIError::ApiError { status, message });
+        }
+
+        let token: TokenResponse = resp.json().await?;
+        let mut state = self.state.lock().await;
+        let previous_refresh_token = state.as_ref().and_then(|s| s.refresh_token.clone());
+        *state = Some(TokenState {
+            refresh_token: token.refresh_token.or(previous_refresh_token),
+            access_token: token.access_token,
+            expires_at: Instant::now() + Duration::from_secs(token.expires_in),
+        });
+        Ok(())
+    }
+}
+
+// =============================================================================
+// Provenance
+// =============================================================================
+
+/// A single link in a provenance chain, as recorded by a role-gate or
+/// ceiling decision.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvenanceRecord {
+    pub id: String,
+    pub record_type: String,
+    pub agent_id: String,
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    #[serde(default)]
+    pub parent_hash: Option<String>,
+    pub hash: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A provenance record together with its parent-link chain, ordered from
+/// `leaf` back to the root.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvenanceChain {
+    pub leaf: ProvenanceRecord,
+    pub chain: Vec<ProvenanceRecord>,
+}
+
+/// The result of walking a provenance chain and recomputing each record's
+/// hash locally, rather than trusting the server's `allowed` flag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationReport {
+    pub verified: bool,
+    pub broken_link_at: Option<String>,
+    pub root_id: Option<String>,
+}
+
+/// Recomputes a record's hash over its canonical fields, independent of
+/// what the server claims `hash` to be.
+fn compute_record_hash(record: &ProvenanceRecord) -> String {
+    let canonical = format!(
+        "{}|{}|{}|{}",
+        record.id,
+        record.record_type,
+        record.agent_id,
+        record.created_at.to_rfc3339(),
+    );
+    to_hex(&Sha256::digest(canonical.as_bytes()))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Walks `chain.leaf` through `chain.chain` (leaf to root) verifying that
+/// each record's stored hash matches its recomputed canonical hash, and
+/// that each record's `parent_hash` matches the hash of its parent. This
+/// proves the chain is an untampered, contiguous audit trail without
+/// relying on the server's `allowed` flag alone.
+pub fn verify_chain(chain: &ProvenanceChain) -> VerificationReport {
+    let records: Vec<&ProvenanceRecord> =
+        std::iter::once(&chain.leaf).chain(chain.chain.iter()).collect();
+
+    for (i, record) in records.iter().enumerate() {
+        if compute_record_hash(record) != record.hash {
+            return VerificationReport {
+                verified: false,
+                broken_link_at: Some(record.id.clone()),
+                root_id: None,
+            };
+        }
+
+        match records.get(i + 1) {
+            Some(parent) => {
+                if record.parent_hash.as_deref() != Some(parent.hash.as_str()) {
+                    return VerificationReport {
+                        verified: false,
+                        broken_link_at: Some(record.id.clone()),
+                        root_id: None,
+                    };
+                }
+            }
+            None => {
+                if record.parent_id.is_some() || record.parent_hash.is_some() {
+                    return VerificationReport {
+                        verified: false,
+                        broken_link_at: Some(record.id.clone()),
+                        root_id: None,
+                    };
+                }
+                return VerificationReport {
+                    verified: true,
+                    broken_link_at: None,
+                    root_id: Some(record.id.clone()),
+                };
+            }
+        }
+    }
+
+    VerificationReport {
+        verified: true,
+        broken_link_at: None,
+        root_id: None,
+    }
+}
+
+// =============================================================================
+// Access Analysis
+// =============================================================================
+
+/// A hypothetical `(agent_id, role, tier, resource_type, requested_amount)`
+/// tuple to evaluate without consuming any ceiling budget.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessQuery {
+    pub agent_id: String,
+    pub role: AgentRole,
+    pub tier: TrustTier,
+    pub resource_type: ResourceType,
+    pub requested_amount: i32,
+}
+
+/// The predicted outcome of an [`AccessQuery`], including whether the
+/// combination is over-privileged or near a ceiling limit.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessFinding {
+    pub agent_id: String,
+    pub decision: Decision,
+    #[serde(default)]
+    pub required_tier: Option<TrustTier>,
+    pub remaining_headroom: i32,
+    pub over_privileged: bool,
+    pub near_limit: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AccessAnalysisRequest<'a> {
+    queries: &'a [AccessQuery],
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AccessAnalysisResponse {
+    findings: Vec<AccessFinding>,
+}
+
 // =============================================================================
 // Errors
 // =============================================================================
@@ -178,16 +921,28 @@ pub enum ACIError {
 
 pub struct ACIClient {
     base_url: String,
-    api_key: Option<String>,
+    auth: Option<Arc<Auth>>,
     client: Client,
+    config: ClientConfig,
+    limiter: RateLimiter,
 }
 
 impl ACIClient {
-    pub fn new(base_url: impl Into<String>, api_key: Option<String>) -> Self {
+    pub fn new(base_url: impl Into<String>, auth: Option<Auth>) -> Self {
+        Self::with_config(base_url, auth, ClientConfig::default())
+    }
+
+    pub fn with_config(
+        base_url: impl Into<String>,
+        auth: Option<Auth>,
+        config: ClientConfig,
+    ) -> Self {
         Self {
             base_url: base_url.into(),
-            api_key,
+            auth: auth.map(Arc::new),
             client: Client::new(),
+            config,
+            limiter: RateLimiter::default(),
         }
     }
 
@@ -196,41 +951,83 @@ impl ACIClient {
         T: Serialize,
         R: for<'de> Deserialize<'de>,
     {
-        let url = format!("{}{}", self.base_url, path);
-
-        let mut req = match method {
-            "GET" => self.client.get(&url),
-            "POST" => self.client.post(&url),
-            "PUT" => self.client.put(&url),
-            "DELETE" => self.client.delete(&url),
-            "PATCH" => self.client.patch(&url),
-            _ => return Err(ACIError::ApiError {
-                status: 0,
-                message: format!("Unsupported method: {}", method),
-            }),
-        };
+        let limit_type = LimitType::classify(path);
+        let mut attempt: u32 = 0;
+        let mut retried_after_refresh = false;
 
-        req = req.header("Content-Type", "application/json");
-        req = req.header("Accept", "application/json");
+        loop {
+            if self.config.respect_rate_limit_headers {
+                self.limiter.wait_if_exhausted(limit_type).await;
+            }
 
-        if let Some(key) = &self.api_key {
-            req = req.header("X-API-Key", key);
-        }
+            let url = format!("{}{}", self.base_url, path);
 
-        if let Some(body) = body {
-            req = req.json(body);
-        }
+            let mut req = match method {
+                "GET" => self.client.get(&url),
+                "POST" => self.client.post(&url),
+                "PUT" => self.client.put(&url),
+                "DELETE" => self.client.delete(&url),
+                "PATCH" => self.client.patch(&url),
+                _ => {
+                    return Err(ACIError::ApiError {
+                        status: 0,
+                        message: format!("Unsupported method: {}", method),
+                    })
+                }
+            };
 
-        let resp = req.send().await?;
-        let status = resp.status().as_u16();
+            req = req.header("Content-Type", "application/json");
+            req = req.header("Accept", "application/json");
 
-        if status >= 400 {
-            let message = resp.text().await.unwrap_or_default();
-            return Err(ACIError::ApiError { status, message });
-        }
+            match self.auth.as_deref() {
+                Some(Auth::ApiKey(key)) => req = req.header("X-API-Key", key),
+                Some(Auth::OAuth2(oauth)) => {
+                    let token = oauth.access_token(&self.client).await?;
+                    req = req.header("Authorization", format!("Bearer {}", token));
+                }
+                None => {}
+            }
+
+            if let Some(body) = body {
+                req = req.json(body);
+            }
 
-        let data = resp.json().await?;
-        Ok(data)
+            let resp = req.send().await?;
+            let status = resp.status().as_u16();
+            let headers = resp.headers().clone();
+
+            if self.config.respect_rate_limit_headers {
+                self.limiter.update_from_headers(limit_type, &headers).await;
+            }
+
+            let oauth = match self.auth.as_deref() {
+                Some(Auth::OAuth2(oauth)) => Some(oauth),
+                _ => None,
+            };
+            if let (true, Some(oauth)) = (status == 401 && !retried_after_refresh, oauth) {
+                oauth.refresh(&self.client).await?;
+                retried_after_refresh = true;
+                continue;
+            }
+
+            let is_retryable = status == 429 || (500..600).contains(&status);
+            if is_retryable && attempt < self.config.max_retries {
+                let wait = parse_retry_after(&headers).unwrap_or_else(|| {
+                    backoff_duration(attempt, self.config.base_backoff, self.config.max_backoff)
+                });
+                tokio::time::sleep(wait).await;
+                attempt += 1;
+                continue;
+            }
+
+            if status >= 400 {
+                let message = resp.text().await.unwrap_or_default();
+                return Err(ACIError::ApiError { status, message });
+            }
+
+            let data = resp.json().await?;
+            return Ok(data);
+        }
     }
 
     /// Get dashboard statistics
@@ -256,6 +1053,243 @@ impl ACIClient {
         self.request("POST", "/api/phase6/ceiling/check", Some(request))
             .await
     }
+
+    /// Fetch a provenance record along with its parent-link chain back to
+    /// the root, for local integrity verification via [`verify_chain`].
+    pub async fn get_provenance(&self, id: &str) -> Result<ProvenanceChain, ACIError> {
+        self.request::<(), ProvenanceChain>(
+            "GET",
+            &format!("/api/phase6/provenance/{}", id),
+            None,
+        )
+        .await
+    }
+
+    /// Evaluate many hypothetical access queries in one batch, without
+    /// consuming any ceiling budget. Useful for auditing trust
+    /// configuration before deploying agents.
+    pub async fn analyze_access(
+        &self,
+        queries: &[AccessQuery],
+    ) -> Result<Vec<AccessFinding>, ACIError> {
+        let body = AccessAnalysisRequest { queries };
+        let resp: AccessAnalysisResponse = self
+            .request("POST", "/api/phase6/access/analyze", Some(&body))
+            .await?;
+        Ok(resp.findings)
+    }
+
+    /// Opens the real-time decision/alert event gateway.
+    pub fn events(&self) -> EventGateway {
+        EventGateway {
+            base_url: self.base_url.clone(),
+            auth: self.auth.clone(),
+        }
+    }
+}
+
+// =============================================================================
+// Event Streaming
+// =============================================================================
+
+/// The kinds of real-time events the gateway can emit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EventKind {
+    RoleGateEvaluated,
+    CeilingExceeded,
+    AlertRaised,
+    ProvenanceRecorded,
+}
+
+/// A real-time event pushed by the gateway, reusing the same decision and
+/// classification types as the request/response API.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all_fields = "camelCase")]
+pub enum Event {
+    RoleGateEvaluated {
+        agent_id: String,
+        role: AgentRole,
+        tier: TrustTier,
+        decision: Decision,
+        occurred_at: DateTime<Utc>,
+    },
+    CeilingExceeded {
+        agent_id: String,
+        resource_type: ResourceType,
+        requested_amount: i32,
+        ceiling: i32,
+        occurred_at: DateTime<Utc>,
+    },
+    AlertRaised {
+        alert_id: String,
+        severity: String,
+        message: String,
+        occurred_at: DateTime<Utc>,
+    },
+    ProvenanceRecorded {
+        provenance_id: String,
+        parent_id: Option<String>,
+        occurred_at: DateTime<Utc>,
+    },
+}
+
+impl Event {
+    pub fn kind(&self) -> EventKind {
+        match self {
+            Event::RoleGateEvaluated { .. } => EventKind::RoleGateEvaluated,
+            Event::CeilingExceeded { .. } => EventKind::CeilingExceeded,
+            Event::AlertRaised { .. } => EventKind::AlertRaised,
+            Event::ProvenanceRecorded { .. } => EventKind::ProvenanceRecorded,
+        }
+    }
+}
+
+/// Which event kinds a subscription should deliver. An empty filter (the
+/// default) delivers every kind.
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter(std::collections::HashSet<EventKind>);
+
+impl EventFilter {
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    pub fn only(kinds: impl IntoIterator<Item = EventKind>) -> Self {
+        Self(kinds.into_iter().collect())
+    }
+
+    fn matches(&self, kind: EventKind) -> bool {
+        self.0.is_empty() || self.0.contains(&kind)
+    }
+}
+
+/// Entry point for the persistent event stream, obtained via [`ACIClient::events`].
+pub struct EventGateway {
+    base_url: String,
+    auth: Option<Arc<Auth>>,
+}
+
+impl EventGateway {
+    /// Opens a persistent WebSocket connection and yields a stream of
+    /// filtered, typed events. The connection reconnects automatically with
+    /// capped exponential backoff if it drops.
+    pub fn subscribe(&self, filter: EventFilter) -> impl Stream<Item = Result<Event, ACIError>> {
+        let ws_url = self
+            .base_url
+            .replacen("https://", "wss://", 1)
+            .replacen("http://", "ws://", 1)
+            + "/api/phase6/events";
+        let auth = self.auth.clone();
+        let http_client = Client::new();
+
+        let (tx, rx) = tokio::sync::mpsc::channel(64);
+        tokio::spawn(run_event_stream(ws_url, auth, http_client, filter, tx));
+        tokio_stream::wrappers::ReceiverStream::new(rx)
+    }
+}
+
+/// Drives a single gateway subscription: connect, relay typed events,
+/// reply to keepalive pings, and reconnect with backoff on disconnect.
+async fn run_event_stream(
+    ws_url: String,
+    auth: Option<Arc<Auth>>,
+    http_client: Client,
+    filter: EventFilter,
+    tx: tokio::sync::mpsc::Sender<Result<Event, ACIError>>,
+) {
+    let mut attempt: u32 = 0;
+
+    while !tx.is_closed() {
+        let mut request = match ws_url.as_str().into_client_request() {
+            Ok(request) => request,
+            Err(e) => {
+                let _ = tx
+                    .send(Err(ACIError::ApiError {
+                        status: 0,
+                        message: format!("invalid event gateway URL: {e}"),
+                    }))
+                    .await;
+                return;
+            }
+        };
+
+        let mut auth_failed = false;
+        match auth.as_deref() {
+            Some(Auth::ApiKey(key)) => {
+                if let Ok(value) = key.parse() {
+                    request.headers_mut().insert("X-API-Key", value);
+                }
+            }
+            Some(Auth::OAuth2(oauth)) => match oauth.access_token(&http_client).await {
+                Ok(token) => {
+                    if let Ok(value) = format!("Bearer {token}").parse() {
+                        request.headers_mut().insert("Authorization", value);
+                    }
+                }
+                Err(e) => {
+                    if tx.send(Err(e)).await.is_err() {
+                        return;
+                    }
+                    auth_failed = true;
+                }
+            },
+            None => {}
+        }
+
+        if !auth_failed {
+            match tokio_tungstenite::connect_async(request).await {
+                Ok((stream, _response)) => {
+                    attempt = 0;
+                    let (mut write, mut read) = stream.split();
+
+                    while let Some(message) = read.next().await {
+                        match message {
+                            Ok(Message::Text(text)) => {
+                                let decoded = serde_json::from_str::<Event>(&text)
+                                    .map_err(ACIError::ParseError);
+                                let deliver = match &decoded {
+                                    Ok(event) => filter.matches(event.kind()),
+                                    Err(_) => true,
+                                };
+                                if deliver && tx.send(decoded).await.is_err() {
+                                    return;
+                                }
+                            }
+                            Ok(Message::Ping(payload)) => {
+                                if write.send(Message::Pong(payload)).await.is_err() {
+                                    break;
+                                }
+                            }
+                            Ok(Message::Close(_)) => break,
+                            Ok(_) => {}
+                            Err(_) => break,
+                        }
+                    }
+                }
+                Err(e) => {
+                    if tx
+                        .send(Err(ACIError::ApiError {
+                            status: 0,
+                            message: format!("event gateway connection failed: {e}"),
+                        }))
+                        .await
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+            }
+        }
+
+        if tx.is_closed() {
+            return;
+        }
+        let wait = backoff_duration(attempt, Duration::from_millis(250), Duration::from_secs(30));
+        tokio::time::sleep(wait).await;
+        attempt = attempt.saturating_add(1).min(16);
+    }
 }
 
 // =============================================================================
@@ -266,10 +1300,10 @@ impl ACIClient {
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Get configuration from environment
     let base_url = env::var("ACI_BASE_URL").unwrap_or_else(|_| "http://localhost:3000".to_string());
-    let api_key = env::var("ACI_API_KEY").ok();
+    let auth = env::var("ACI_API_KEY").ok().map(Auth::ApiKey);
 
     // Create client
-    let client = ACIClient::new(&base_url, api_key);
+    let client = ACIClient::new(&base_url, auth);
 
     println!("Vorion ACI Rust SDK Example");
     println!("===========================");
@@ -303,6 +1337,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             ("resourceId".to_string(), "dataset_001".to_string()),
             ("action".to_string(), "read".to_string()),
         ])),
+        scopes: Some(Scopes::from_iter(["data:read"])),
     };
 
     match client.evaluate_role_gate(&role_gate_req).await {
@@ -343,6 +1378,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         role: AgentRole::SystemAdmin,
         tier: TrustTier::Basic,
         context: None,
+        scopes: None,
     };
 
     match client.evaluate_role_gate(&denied_req).await {